@@ -0,0 +1,17 @@
+fn main() {
+    let mut a = 10;
+    let b = &a;
+    let c = &mut a; // cannot borrow `a` as mutable because it is also borrowed as immutable
+    let mut d = 20;
+    let e = &mut d;
+    let f = &d; // cannot borrow `d` as immutable because it is also borrowed as mutable
+    let owner = 30;
+    let g = &owner;
+    let moved = owner; // cannot move out of `owner` because it is borrowed
+    displayi32(*b);
+    displayi32(*c);
+    displayi32(*e);
+    displayi32(*f);
+    displayi32(*g);
+    displayi32(moved);
+}
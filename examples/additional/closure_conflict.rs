@@ -0,0 +1,12 @@
+fn main() {
+    let mut x = 10;
+    let r = || {
+        let a = &x;
+        let b = &mut x; // cannot borrow `x` as mutable because it is also borrowed as immutable
+        displayi32(*a);
+        displayi32(*b);
+    };
+    let outside = &mut x; // cannot borrow `x` as mutable because it is also borrowed by closure `r`
+    r();
+    displayi32(*outside);
+}
@@ -0,0 +1,10 @@
+// Output: 2
+fn main() {
+    let mut counter = 0;
+    let mut inc = || {
+        counter += 1; // inferred capture mode: mutable borrow of `counter`
+    };
+    inc();
+    inc();
+    displayi32(counter);
+}
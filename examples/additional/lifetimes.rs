@@ -0,0 +1,11 @@
+fn save_ref<'a>(refr: &'a i32, to: &mut [&'a i32]) {
+    to[0] = refr;
+}
+
+fn main() {
+    let mut a = 32;
+    let mut slots: [&i32; 1] = [&a];
+    save_ref(&a, &mut slots);
+    a = 64; // cannot assign to `a` because it is borrowed (kept live by `'a` through `slots`)
+    displayi32(*slots[0]);
+}
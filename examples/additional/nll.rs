@@ -0,0 +1,8 @@
+// Output: 32 64
+fn main() {
+    let mut a = 32;
+    let b = &a;
+    println!("{}", b);
+    a = 64; // ok: `b`'s region ends at its last use above, not at end of scope
+    println!("{}", a);
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let ref mut refr = 1;
+    let a = &*refr;
+    *refr = 3; // cannot assign to `*refr` because it is borrowed
+    displayi32(*a);
+}
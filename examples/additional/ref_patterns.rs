@@ -0,0 +1,11 @@
+// Output: 5 6
+fn takes_ref(ref x: i32) -> i32 {
+    *x + 1
+}
+
+fn main() {
+    let ref init = 5;
+    displayi32(*init);
+    let result = takes_ref(5);
+    displayi32(result);
+}
@@ -0,0 +1,8 @@
+fn main() {
+    let mut s: String = "hello".to_string();
+    let slice: &str = &s;
+    s = "world".to_string(); // cannot assign to `s` because it is borrowed
+    let moved: String = s; // cannot move out of `s` because it is borrowed
+    displaystr(slice);
+    displaystr(&moved);
+}
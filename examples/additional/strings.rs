@@ -0,0 +1,7 @@
+// Output: hello world
+fn main() {
+    let s: String = "hello".to_string();
+    let slice: &str = &s;
+    displaystr(slice);
+    displaystr(" world");
+}
@@ -0,0 +1,15 @@
+struct Point {
+    a: i32,
+    b: i32,
+}
+
+fn main() {
+    let mut x = Point { a: 1, b: 2 };
+    let ra = &mut x.a;
+    let ra2 = &x.a; // cannot borrow `x.a` as immutable because it is also borrowed as mutable
+    let rb = &x.b;
+    let whole = &mut x; // cannot borrow `x` as mutable because it is also borrowed through `x.a`
+    displayi32(*ra);
+    displayi32(*ra2);
+    displayi32(*rb);
+}
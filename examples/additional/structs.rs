@@ -0,0 +1,14 @@
+// Output: 11 22
+struct Point {
+    a: i32,
+    b: i32,
+}
+
+fn main() {
+    let mut x = Point { a: 1, b: 2 };
+    let (ra, rb) = (&mut x.a, &mut x.b); // ok: disjoint fields
+    *ra += 10;
+    *rb += 20;
+    displayi32(x.a);
+    displayi32(x.b);
+}